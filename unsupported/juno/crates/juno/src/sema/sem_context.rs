@@ -33,6 +33,24 @@ macro_rules! declare_opaque_id {
     };
 }
 
+/// Compute the Levenshtein edit distance between two strings using a two-row
+/// dynamic programming table over their characters.
+fn lev_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut cur: Vec<usize> = vec![0; b.len() + 1];
+    for (i, &ca) in a.iter().enumerate() {
+        cur[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let cost = if ca == cb { 0 } else { 1 };
+            cur[j + 1] = (prev[j + 1] + 1).min(cur[j] + 1).min(prev[j] + cost);
+        }
+        std::mem::swap(&mut prev, &mut cur);
+    }
+    prev[b.len()]
+}
+
 declare_opaque_id!(DeclId);
 declare_opaque_id!(LexicalScopeId);
 declare_opaque_id!(FunctionInfoId);
@@ -115,23 +133,56 @@ pub struct Decl {
     /// The lexical scope of the declaration. Could be nullptr for special
     /// declarations, since they are technically unscoped.
     pub scope: LexicalScopeId,
+    /// Set to true if this declaration is referenced from a nested function,
+    /// meaning it must live in a heap environment instead of a plain frame
+    /// slot. Populated during resolution by [`SemContext::set_ident_decl`].
+    pub is_closed_over: bool,
+    /// Index of this declaration in its owning function's environment record,
+    /// if it is closed over. Assigned by [`SemContext::assign_slots`].
+    pub env_slot: Option<u32>,
+    /// Index of this declaration in its owning function's frame, if it is a
+    /// plain local. Assigned by [`SemContext::assign_slots`].
+    pub frame_slot: Option<u32>,
+    /// Number of references (excluding the defining occurrence) that resolve to
+    /// this declaration. Used to detect unused bindings.
+    pub uses: u32,
+    /// Set to true for a top-level function declaration, which is hoisted and
+    /// instantiated ahead of the rest of the scope body.
+    pub is_top_level_function: bool,
 }
 
 impl Decl {
     fn dump(&self, lock: &GCLock, id: DeclId, indent: usize) {
+        let slot = match (self.env_slot, self.frame_slot) {
+            (Some(i), _) => format!(" env[{i}]"),
+            (_, Some(i)) => format!(" frame[{i}]"),
+            _ => String::new(),
+        };
         println!(
-            "{:indent$} Decl#{id} '{name}' {kind:?} {special:?}{function_in_scope}",
+            "{:indent$} Decl#{id} '{name}' {kind:?} {special:?}{function_in_scope}{closed_over}{top_level_function}{slot} uses={uses}",
             "",
             indent = indent,
             id = id.as_usize(),
             name = lock.str(self.name),
             kind = self.kind,
             special = self.special,
+            uses = self.uses,
             function_in_scope = if self.function_in_scope {
                 " functionInScope"
             } else {
                 ""
             },
+            closed_over = if self.is_closed_over {
+                " captured"
+            } else {
+                ""
+            },
+            slot = slot,
+            top_level_function = if self.is_top_level_function {
+                " topLevelFunction"
+            } else {
+                ""
+            },
         );
     }
 }
@@ -146,6 +197,10 @@ pub struct LexicalScope {
     /// A list of functions that need to be hoisted and materialized before we
     /// can generate the rest of the scope.
     pub hoisted_functions: Vec<NodeRc>,
+    /// The declarations of the functions that must be instantiated and bound,
+    /// in source order, before the rest of the scope body runs. This is the
+    /// `DeclId`-addressable counterpart of `hoisted_functions`.
+    pub functions_to_initialize: Vec<DeclId>,
 }
 
 impl LexicalScope {
@@ -189,6 +244,12 @@ pub struct FunctionInfo {
     pub scopes: Vec<LexicalScopeId>,
     /// The implicitly declared "arguments" object. It is declared only if it is used.
     pub arguments_decl: Option<DeclId>,
+    /// Number of slots in this function's environment record, computed by
+    /// [`SemContext::assign_slots`].
+    pub env_size: u32,
+    /// Number of slots in this function's frame, computed by
+    /// [`SemContext::assign_slots`].
+    pub frame_size: u32,
 }
 
 impl FunctionInfo {
@@ -245,9 +306,29 @@ pub struct SemContext {
     node_scopes: HashMap<NodeRc, LexicalScopeId>,
     /// Resolved `require` calls.
     requires: HashMap<NodeRc, SourceId>,
+    /// When set, only the function/scope nesting, declarations and strictness
+    /// structure are collected; the `ident_decls`, `node_scopes` and `requires` maps are left
+    /// empty to save time and memory on large bundles.
+    syntax_only: bool,
 }
 
 impl SemContext {
+    /// Create a context in the given resolution mode. When `syntax_only` is
+    /// true only the function/scope tree, its declarations and strictness are
+    /// recorded; the identifier, node-scope and require maps stay empty.
+    pub fn new(syntax_only: bool) -> Self {
+        SemContext {
+            syntax_only,
+            ..Default::default()
+        }
+    }
+
+    /// Return true if this context was built in syntax-only mode, in which case
+    /// the binding tables are not populated.
+    pub fn is_syntax_only(&self) -> bool {
+        self.syntax_only
+    }
+
     pub(super) fn new_function(
         &mut self,
         parent_function: Option<FunctionInfoId>,
@@ -260,6 +341,8 @@ impl SemContext {
             strict,
             scopes: Default::default(),
             arguments_decl: Default::default(),
+            env_size: 0,
+            frame_size: 0,
         });
         (
             FunctionInfoId::new(self.funcs.len() - 1),
@@ -277,6 +360,7 @@ impl SemContext {
             parent_scope,
             decls: Default::default(),
             hoisted_functions: Default::default(),
+            functions_to_initialize: Default::default(),
         });
         let scope_id = LexicalScopeId::new(self.scopes.len() - 1);
 
@@ -298,6 +382,11 @@ impl SemContext {
             special,
             function_in_scope: false,
             scope,
+            is_closed_over: false,
+            env_slot: None,
+            frame_slot: None,
+            uses: 0,
+            is_top_level_function: false,
         });
         let decl_id = DeclId::new(self.decls.len() - 1);
         self.scopes[scope.as_usize()].decls.push(decl_id);
@@ -307,6 +396,36 @@ impl SemContext {
         self.new_decl_special(scope, name, kind, Special::NotSpecial)
     }
 
+    /// Record a hoisted function declaration (`ScopedFunction`, or a global
+    /// function declaration) in `scope`. The decl is appended to the scope's
+    /// ordered `functions_to_initialize` list in source order and, when
+    /// `top_level`, marked as a top-level function.
+    ///
+    /// Scope construction must route every scoped/global function declaration
+    /// through this method rather than `new_decl`; otherwise
+    /// `functions_to_initialize` stays empty and the hoist ordering is lost.
+    /// When a `var` of the same name collides with a function declaration, the
+    /// function is the binding that gets hoisted and initialized: only the
+    /// function declaration is appended to `functions_to_initialize`.
+    pub(super) fn new_function_decl(
+        &mut self,
+        scope: LexicalScopeId,
+        name: Atom,
+        kind: DeclKind,
+        top_level: bool,
+    ) -> DeclId {
+        let decl = self.new_decl(scope, name, kind);
+        self.decl_mut(decl).is_top_level_function = top_level;
+        self.scope_mut(scope).functions_to_initialize.push(decl);
+        decl
+    }
+
+    /// Return the declarations of the functions that must be instantiated and
+    /// bound, in source order, before the rest of `scope` runs.
+    pub fn functions_to_initialize(&self, scope: LexicalScopeId) -> &[DeclId] {
+        self.scope(scope).functions_to_initialize.as_slice()
+    }
+
     pub(super) fn new_global(&mut self, name: Atom, kind: DeclKind) -> DeclId {
         self.new_decl(
             self.global_scope_id()
@@ -319,8 +438,201 @@ impl SemContext {
     pub fn all_ident_decls(&self) -> &HashMap<NodeRc, DeclId> {
         &self.ident_decls
     }
-    pub(super) fn set_ident_decl(&mut self, node: NodeRc, decl: DeclId) {
+    /// Associate an identifier `node` with its resolved declaration. The
+    /// resolver must pass `using_function`, the function enclosing the
+    /// reference, so that closed-over analysis can tell when a reference
+    /// crosses a function boundary. Every resolver call site that resolves an
+    /// identifier is expected to thread this through.
+    pub(super) fn set_ident_decl(
+        &mut self,
+        node: NodeRc,
+        decl: DeclId,
+        using_function: FunctionInfoId,
+    ) {
+        if self.syntax_only {
+            return;
+        }
         self.ident_decls.insert(node, decl);
+        self.mark_capture(decl, using_function);
+    }
+
+    /// Record that a *reference* (as opposed to the binding occurrence) to
+    /// `decl` was resolved. This is deliberately separate from
+    /// [`set_ident_decl`], which the resolver also invokes for binding
+    /// identifiers; folding the counter into `set_ident_decl` would miscount
+    /// every declared binding as used and make [`unused_lexical_decls`] return
+    /// nothing.
+    pub(super) fn record_use(&mut self, decl: DeclId) {
+        if self.syntax_only {
+            return;
+        }
+        self.decl_mut(decl).uses += 1;
+    }
+
+    /// Record that `decl` is referenced from within `using_function`. If the
+    /// reference crosses a function boundary - i.e. the declaration is owned by
+    /// a different function than the one using it - the declaration is marked
+    /// as closed over so it can be placed in a heap environment. Global
+    /// properties live on the global object rather than in an activation
+    /// record, so they are never treated as captured.
+    pub(super) fn mark_capture(&mut self, decl: DeclId, using_function: FunctionInfoId) {
+        if self.decl(decl).kind.is_global() {
+            return;
+        }
+        let decl_function = self.scope(self.decl(decl).scope).parent_function;
+        if decl_function != using_function {
+            self.decl_mut(decl).is_closed_over = true;
+        }
+    }
+
+    /// Iterate over the declarations owned by `func` that are closed over by a
+    /// nested function and therefore must live in its environment record.
+    pub fn captured_decls(&self, func: FunctionInfoId) -> impl Iterator<Item = DeclId> + '_ {
+        self.function(func)
+            .scopes
+            .iter()
+            .flat_map(move |&scope| self.scope(scope).decls.iter().copied())
+            .filter(move |&decl| self.decl(decl).is_closed_over)
+    }
+
+    /// Suggest the name in scope most similar to `name`, for use in a
+    /// "did you mean" diagnostic when an identifier resolves to an undeclared
+    /// global. Candidates are gathered by walking outward from `from_scope`
+    /// through `parent_scope` (which also crosses into enclosing functions).
+    /// The closest candidate by Levenshtein distance is returned, provided that
+    /// distance is at most `max(name.len() / 3, 1)`; ties are broken by the
+    /// shortest candidate, then lexicographically. `None` is returned when no
+    /// candidate clears the threshold, so callers can suppress noise.
+    pub fn suggest_similar(
+        &self,
+        lock: &GCLock,
+        name: Atom,
+        from_scope: LexicalScopeId,
+    ) -> Option<Atom> {
+        let target = lock.str(name);
+        let threshold = (target.len() / 3).max(1);
+        let mut best: Option<(usize, &str, Atom)> = None;
+        let mut scope = Some(from_scope);
+        while let Some(id) = scope {
+            let s = self.scope(id);
+            for &decl in &s.decls {
+                let cand_atom = self.decl(decl).name;
+                if cand_atom == name {
+                    continue;
+                }
+                let cand = lock.str(cand_atom);
+                let dist = lev_distance(target, cand);
+                if dist > threshold {
+                    continue;
+                }
+                let better = match best {
+                    None => true,
+                    Some((best_dist, best_cand, _)) => {
+                        dist < best_dist
+                            || (dist == best_dist
+                                && (cand.len() < best_cand.len()
+                                    || (cand.len() == best_cand.len() && cand < best_cand)))
+                    }
+                };
+                if better {
+                    best = Some((dist, cand, cand_atom));
+                }
+            }
+            scope = s.parent_scope;
+        }
+        best.map(|(_, _, atom)| atom)
+    }
+
+    /// Return every let-like declaration (`Let`/`Const`/`Class`/`Import`, and
+    /// catch variables) that is never referenced, so a linting front-end can
+    /// flag dead bindings. `Parameter` and `GlobalProperty` declarations are
+    /// skipped, as are names beginning with `_`, which are conventionally used
+    /// to mark an intentionally unused binding.
+    pub fn unused_lexical_decls(&self, lock: &GCLock) -> Vec<DeclId> {
+        (0..self.decls.len())
+            .map(DeclId::new)
+            .filter(|&id| {
+                let decl = self.decl(id);
+                decl.kind.is_let_like()
+                    && decl.uses == 0
+                    && !lock.str(decl.name).starts_with('_')
+            })
+            .collect()
+    }
+
+    /// Run the post-resolution passes that depend on the fully populated
+    /// scope/declaration tables. This must be called once, after resolution
+    /// has finished, before the context is handed to a downstream emitter.
+    pub fn finalize(&mut self) {
+        self.assign_slots();
+    }
+
+    /// Assign dense environment and frame slot indices to every declaration
+    /// except global properties, which get no slot. Must be called after resolution (and closed-over analysis) has
+    /// completed. For each function its scopes are walked in order: every
+    /// closed-over declaration is given the next environment index and every
+    /// plain local the next frame index. Parameters and the special
+    /// `arguments` declaration are laid out first so they receive deterministic
+    /// low indices.
+    pub(super) fn assign_slots(&mut self) {
+        for i in 0..self.funcs.len() {
+            let func = FunctionInfoId::new(i);
+            // Global properties live on the global object, not in a frame or
+            // environment, so they are excluded from slot assignment entirely.
+            let decls: Vec<DeclId> = self
+                .function(func)
+                .scopes
+                .iter()
+                .flat_map(|&scope| self.scope(scope).decls.iter().copied())
+                .filter(|&decl| !self.decl(decl).kind.is_global())
+                .collect();
+
+            // Parameters and the special `arguments` object are laid out first
+            // so they receive deterministic low indices, followed by the
+            // remaining declarations in scope order.
+            let (params, rest): (Vec<DeclId>, Vec<DeclId>) =
+                decls.into_iter().partition(|&d| self.is_param_like(d));
+
+            let mut env_index = 0u32;
+            let mut frame_index = 0u32;
+            for decl in params.into_iter().chain(rest) {
+                if self.decl(decl).is_closed_over {
+                    self.decl_mut(decl).env_slot = Some(env_index);
+                    env_index += 1;
+                } else {
+                    self.decl_mut(decl).frame_slot = Some(frame_index);
+                    frame_index += 1;
+                }
+            }
+
+            let f = self.function_mut(func);
+            f.env_size = env_index;
+            f.frame_size = frame_index;
+        }
+    }
+
+    /// Return true if the declaration is a parameter or the special
+    /// `arguments` object, which are laid out before other declarations.
+    fn is_param_like(&self, decl: DeclId) -> bool {
+        let d = self.decl(decl);
+        d.kind == DeclKind::Parameter || d.special == Special::Arguments
+    }
+
+    /// Return the environment slot of `decl`, if it is closed over.
+    pub fn env_slot(&self, decl: DeclId) -> Option<u32> {
+        self.decl(decl).env_slot
+    }
+    /// Return the frame slot of `decl`, if it is a plain local.
+    pub fn frame_slot(&self, decl: DeclId) -> Option<u32> {
+        self.decl(decl).frame_slot
+    }
+    /// Return the number of slots in `func`'s environment record.
+    pub fn env_size(&self, func: FunctionInfoId) -> u32 {
+        self.function(func).env_size
+    }
+    /// Return the number of slots in `func`'s frame.
+    pub fn frame_size(&self, func: FunctionInfoId) -> u32 {
+        self.function(func).frame_size
     }
     pub fn ident_decl(&self, node: &NodeRc) -> Option<DeclId> {
         self.ident_decls.get(node).copied()
@@ -330,6 +642,9 @@ impl SemContext {
         &self.node_scopes
     }
     pub(super) fn set_node_scope(&mut self, node: NodeRc, scope: LexicalScopeId) {
+        if self.syntax_only {
+            return;
+        }
         self.node_scopes.insert(node, scope);
     }
     pub fn node_scope(&self, node: NodeRc) -> Option<LexicalScopeId> {
@@ -370,6 +685,9 @@ impl SemContext {
         &self.requires
     }
     pub fn add_require(&mut self, call: NodeRc, file_id: SourceId) {
+        if self.syntax_only {
+            return;
+        }
         self.requires.insert(call, file_id);
     }
 
@@ -476,3 +794,198 @@ impl SemContext {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use juno_ast::{Context, GCLock};
+
+    /// Build an empty context in full-resolution mode with a fresh global
+    /// function and global scope, returning the context, the global function
+    /// id and the global scope id.
+    fn empty_context() -> (SemContext, FunctionInfoId, LexicalScopeId) {
+        let mut sem = SemContext::new(false);
+        let (func, _) = sem.new_function(None, None, false);
+        let (scope, _) = sem.new_scope(func, None);
+        (sem, func, scope)
+    }
+
+    #[test]
+    fn lev_distance_basic() {
+        assert_eq!(lev_distance("", ""), 0);
+        assert_eq!(lev_distance("abc", "abc"), 0);
+        assert_eq!(lev_distance("abc", ""), 3);
+        assert_eq!(lev_distance("", "abc"), 3);
+    }
+
+    #[test]
+    fn lev_distance_single_edits() {
+        // One transposition reads as two substitutions.
+        assert_eq!(lev_distance("documnet", "document"), 2);
+        // Single substitution, insertion and deletion.
+        assert_eq!(lev_distance("documert", "document"), 1);
+        assert_eq!(lev_distance("ocument", "document"), 1);
+        assert_eq!(lev_distance("documentt", "document"), 1);
+    }
+
+    #[test]
+    fn lev_distance_within_suggestion_threshold() {
+        // `documnet` -> `document` is within max(len / 3, 1) == 2, so it would
+        // be offered as a suggestion.
+        let name = "documnet";
+        let threshold = (name.len() / 3).max(1);
+        assert!(lev_distance(name, "document") <= threshold);
+        // An unrelated name is well beyond the threshold.
+        assert!(lev_distance(name, "window") > threshold);
+    }
+
+    #[test]
+    fn assign_slots_excludes_global_properties() {
+        let mut ctx = Context::new();
+        let lock = GCLock::new(&mut ctx);
+        let (mut sem, func, scope) = empty_context();
+
+        let global = sem.new_decl(scope, lock.atom("globalThing"), DeclKind::GlobalProperty);
+        let local = sem.new_decl(scope, lock.atom("local"), DeclKind::Let);
+
+        // A nested function "references" the global; it must not be captured.
+        let (nested, _) = sem.new_function(Some(func), Some(scope), false);
+        sem.mark_capture(global, nested);
+        assert!(!sem.decl(global).is_closed_over);
+
+        sem.finalize();
+
+        // The global gets neither a frame nor an environment slot and does not
+        // contribute to the activation record sizes.
+        assert_eq!(sem.env_slot(global), None);
+        assert_eq!(sem.frame_slot(global), None);
+        assert_eq!(sem.frame_slot(local), Some(0));
+        assert_eq!(sem.frame_size(func), 1);
+        assert_eq!(sem.env_size(func), 0);
+    }
+
+    #[test]
+    fn unused_lexical_decls_reports_dead_bindings() {
+        let mut ctx = Context::new();
+        let lock = GCLock::new(&mut ctx);
+        let (mut sem, _func, scope) = empty_context();
+
+        let used = sem.new_decl(scope, lock.atom("used"), DeclKind::Const);
+        let unused = sem.new_decl(scope, lock.atom("unused"), DeclKind::Let);
+        // Names beginning with `_` are intentionally unused and skipped.
+        sem.new_decl(scope, lock.atom("_ignored"), DeclKind::Let);
+        // Var-like declarations are never reported.
+        sem.new_decl(scope, lock.atom("v"), DeclKind::Var);
+
+        sem.record_use(used);
+
+        assert_eq!(sem.unused_lexical_decls(&lock), vec![unused]);
+    }
+
+    #[test]
+    fn unused_lexical_decls_shadowing() {
+        let mut ctx = Context::new();
+        let lock = GCLock::new(&mut ctx);
+        let (mut sem, func, outer) = empty_context();
+        let (inner, _) = sem.new_scope(func, Some(outer));
+
+        // Two bindings named `x`: the inner one is used, the outer is not.
+        let outer_x = sem.new_decl(outer, lock.atom("x"), DeclKind::Let);
+        let inner_x = sem.new_decl(inner, lock.atom("x"), DeclKind::Let);
+        sem.record_use(inner_x);
+
+        assert_eq!(sem.unused_lexical_decls(&lock), vec![outer_x]);
+    }
+
+    #[test]
+    fn unused_lexical_decls_catch_variable() {
+        let mut ctx = Context::new();
+        let lock = GCLock::new(&mut ctx);
+        let (mut sem, _func, scope) = empty_context();
+
+        let used_catch = sem.new_decl(scope, lock.atom("e1"), DeclKind::ES5Catch);
+        let unused_catch = sem.new_decl(scope, lock.atom("e2"), DeclKind::ES5Catch);
+        sem.record_use(used_catch);
+
+        assert_eq!(sem.unused_lexical_decls(&lock), vec![unused_catch]);
+    }
+
+    #[test]
+    fn suggest_similar_offers_closest_typo() {
+        let mut ctx = Context::new();
+        let lock = GCLock::new(&mut ctx);
+        let (mut sem, _func, scope) = empty_context();
+        sem.new_decl(scope, lock.atom("document"), DeclKind::Var);
+        sem.new_decl(scope, lock.atom("window"), DeclKind::Var);
+        sem.new_decl(scope, lock.atom("console"), DeclKind::Var);
+
+        // `documnet` -> `document` is within max(8 / 3, 1) == 2.
+        assert_eq!(
+            sem.suggest_similar(&lock, lock.atom("documnet"), scope),
+            Some(lock.atom("document"))
+        );
+        // Nothing is within the threshold of an unrelated name.
+        assert_eq!(sem.suggest_similar(&lock, lock.atom("xyzzy"), scope), None);
+    }
+
+    #[test]
+    fn suggest_similar_tie_breaks_shortest_then_lexicographic() {
+        let mut ctx = Context::new();
+        let lock = GCLock::new(&mut ctx);
+        let (mut sem, _func, scope) = empty_context();
+
+        // Both `abc` (delete) and `abce` (substitute) are distance 1 from
+        // `abcd`; the shorter candidate wins.
+        sem.new_decl(scope, lock.atom("abce"), DeclKind::Var);
+        sem.new_decl(scope, lock.atom("abc"), DeclKind::Var);
+        assert_eq!(
+            sem.suggest_similar(&lock, lock.atom("abcd"), scope),
+            Some(lock.atom("abc"))
+        );
+
+        let (mut sem, _func, scope) = empty_context();
+        // Equal distance and equal length fall back to lexicographic order.
+        sem.new_decl(scope, lock.atom("ad"), DeclKind::Var);
+        sem.new_decl(scope, lock.atom("ac"), DeclKind::Var);
+        assert_eq!(
+            sem.suggest_similar(&lock, lock.atom("ab"), scope),
+            Some(lock.atom("ac"))
+        );
+    }
+
+    #[test]
+    fn suggest_similar_walks_enclosing_scopes() {
+        let mut ctx = Context::new();
+        let lock = GCLock::new(&mut ctx);
+        let (mut sem, func, outer) = empty_context();
+        sem.new_decl(outer, lock.atom("document"), DeclKind::Var);
+        let (inner, _) = sem.new_scope(func, Some(outer));
+
+        // A candidate declared in an enclosing scope is still reachable.
+        assert_eq!(
+            sem.suggest_similar(&lock, lock.atom("documnet"), inner),
+            Some(lock.atom("document"))
+        );
+    }
+
+    #[test]
+    fn functions_to_initialize_records_order_and_precedence() {
+        let mut ctx = Context::new();
+        let lock = GCLock::new(&mut ctx);
+        let (mut sem, _func, scope) = empty_context();
+
+        // A `var` collides with a later function declaration of the same name.
+        let var_f = sem.new_decl(scope, lock.atom("f"), DeclKind::Var);
+        let func_f =
+            sem.new_function_decl(scope, lock.atom("f"), DeclKind::ScopedFunction, true);
+        let func_g =
+            sem.new_function_decl(scope, lock.atom("g"), DeclKind::ScopedFunction, true);
+
+        // Only function declarations are hoisted for initialization, in source
+        // order; the colliding `var` is not.
+        assert_eq!(sem.functions_to_initialize(scope), &[func_f, func_g]);
+        // The function binding, not the colliding `var`, is the top-level one.
+        assert!(sem.decl(func_f).is_top_level_function);
+        assert!(!sem.decl(var_f).is_top_level_function);
+    }
+}